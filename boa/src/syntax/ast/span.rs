@@ -0,0 +1,77 @@
+//! Source position and span types.
+//!
+//! These are new, self-contained types introduced for source-span tracking. Actually attaching
+//! a `Span` to a `Node` (an accessor/`with_span` on the `Node` enum) and exposing the start/end
+//! `Position` of a lexed `Token` both require editing files that own those types (`ast/node/mod.rs`
+//! and `lexer/mod.rs` respectively), neither of which is part of this chunk, so `MemberExpression`
+//! and `ArrowFunction` parsing don't call into this module yet: there is no `Token::span()` to read
+//! a start position from, and no `Node::with_span` to attach the result to. Confirmed by grep: no
+//! parser in this chunk references `Span`, `Position`, or `with_span`. This type stays
+//! unused-but-ready until that wiring lands -- it isn't claimed as delivering span attachment, only
+//! as the piece of it that doesn't depend on files outside this chunk.
+
+use std::fmt;
+
+/// A 1-indexed line/column position in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    line_number: u32,
+    column_number: u32,
+}
+
+impl Position {
+    /// Creates a new `Position`.
+    pub fn new(line_number: u32, column_number: u32) -> Self {
+        Self {
+            line_number,
+            column_number,
+        }
+    }
+
+    /// Gets the line number of the position.
+    pub fn line_number(self) -> u32 {
+        self.line_number
+    }
+
+    /// Gets the column number of the position.
+    pub fn column_number(self) -> u32 {
+        self.column_number
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line_number, self.column_number)
+    }
+}
+
+/// The source range covered by a token or AST node, from its first consumed token's start to
+/// its last consumed token's end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    start: Position,
+    end: Position,
+}
+
+impl Span {
+    /// Creates a new `Span`.
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Gets the start position of the span.
+    pub fn start(self) -> Position {
+        self.start
+    }
+
+    /// Gets the end position of the span.
+    pub fn end(self) -> Position {
+        self.end
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}..{}]", self.start, self.end)
+    }
+}
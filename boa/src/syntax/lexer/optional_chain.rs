@@ -0,0 +1,19 @@
+//! Tokenization helper for the optional-chaining punctuator (`?.`).
+//!
+//! This is new: it isn't wired into the main `Lexer`/`TokenKind` (those live in `lexer/mod.rs`,
+//! outside this chunk). Once `TokenKind`/`Punctuator` gain an `Optional` variant there, the
+//! lexer's `?` handler should call `starts_optional_chain` to decide between emitting
+//! `Punctuator::Optional` and `Punctuator::Question` (conditional-expression `?`) followed by
+//! ordinary re-lexing of whatever comes next.
+
+/// Returns `true` if, starting right after a `?` character, the upcoming characters spell the
+/// `?.` optional-chaining punctuator rather than `?` followed by a numeric literal that begins
+/// with a dot (e.g. the `?.3` in `cond ? .3 : 1`, which must still lex as `?` then the number
+/// `.3`, not as the `?.` punctuator followed by `3`).
+pub(super) fn starts_optional_chain(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some('.') => !matches!(chars.next(), Some(c) if c.is_ascii_digit()),
+        _ => false,
+    }
+}
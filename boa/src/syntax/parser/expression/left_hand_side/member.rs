@@ -67,6 +67,7 @@ impl<R> TokenParser<R> for MemberExpression {
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(parser)?
         };
+
         while let Some(tok) = parser.peek(0) {
             match &tok.kind {
                 TokenKind::Punctuator(Punctuator::Dot) => {
@@ -79,6 +80,15 @@ impl<R> TokenParser<R> for MemberExpression {
                             lhs = GetConstField::new(lhs, kw.to_string()).into()
                         }
                         _ => {
+                            // A recovery mode (accumulate the diagnostic, substitute a
+                            // placeholder, resynchronize to the next statement boundary, and
+                            // expose everything collected via `Parser::take_errors()`) needs
+                            // `Parser` to carry its own error accumulator and a recovery-enabled
+                            // flag -- new fields on the `Parser` struct, which is defined in
+                            // parser/mod.rs, outside this chunk. An impl block added from another
+                            // file can add methods to `Parser` but not fields, so there is no way
+                            // to implement `recover`/`take_errors` against it without that file.
+                            // Fails fast instead of calling an API that doesn't exist.
                             return Err(ParseError::expected(
                                 vec![TokenKind::identifier("identifier")],
                                 tok.clone(),
@@ -0,0 +1,107 @@
+//! Conformance harness for the official [test262-parser-tests] corpus.
+//!
+//! This only exercises the parser (no interpreter execution, no re-serialize/re-parse round
+//! trip): `pass`/`pass-explicit` cases must parse successfully, `fail` cases must produce a
+//! `ParseError`, and `early` cases are tracked separately (see [`early`]) because this parser
+//! doesn't run the early-error static-semantics pass that directory actually tests.
+//!
+//! This is meant to be gated behind a `test262` Cargo feature (declared in `Cargo.toml`, along
+//! with the corpus vendored as a git submodule at `tests/fixtures/test262-parser-tests`) so a
+//! normal `cargo test` doesn't require the submodule to be checked out. There is no `Cargo.toml`
+//! anywhere in this chunk to declare that feature in, so the `#![cfg(feature = "test262")]`
+//! below currently has nothing to turn it on: this file compiles out of every build until a
+//! manifest exists to add the feature and the submodule to. `run_directory` still treats a
+//! missing corpus checkout as a hard failure rather than a silent pass, so once the feature
+//! does get wired up, "forgot to init the submodule" fails loudly instead of reporting green
+//! with zero cases run.
+//!
+//! [test262-parser-tests]: https://github.com/tc39/test262-parser-tests
+
+#![cfg(feature = "test262")]
+
+use boa::syntax::{ast::node::StatementList, parser::Parser};
+use std::{fs, path::Path};
+
+const CORPUS_ROOT: &str = "tests/fixtures/test262-parser-tests";
+
+/// Cases that are known to fail today. `run_directory` skips them without parsing them, so a
+/// case listed here that starts to pass is not reported. Unverified against an actual
+/// checkout of the corpus -- this chunk has no submodule and no Cargo.toml to fetch one with --
+/// so treat the exact filenames as best-effort until someone runs this with the corpus present.
+const KNOWN_FAILURES: &[&str] = &[
+    // Arrow-function cover grammar edge cases not yet handled by `ArrowFunction::parse`.
+    "pass/3227044a6f3f9811.js",
+    // `new.target` parsing.
+    "fail/6aa6a7a5be46bda6.js",
+];
+
+fn parse(src: &str) -> Result<StatementList, String> {
+    Parser::new(src.as_bytes())
+        .parse_all()
+        .map_err(|e| e.to_string())
+}
+
+fn run_directory(dir: &str, expect_success: bool) {
+    let dir_path = Path::new(CORPUS_ROOT).join(dir);
+    assert!(
+        dir_path.exists(),
+        "`{}` does not exist -- is the test262-parser-tests submodule checked out? \
+         (git submodule update --init {})",
+        dir_path.display(),
+        CORPUS_ROOT
+    );
+
+    let mut failures = Vec::new();
+    for entry in fs::read_dir(&dir_path).expect("failed to read test262-parser-tests directory") {
+        let path = entry.expect("failed to read directory entry").path();
+        if path.extension().and_then(|e| e.to_str()) != Some("js") {
+            continue;
+        }
+
+        let rel = format!("{}/{}", dir, path.file_name().unwrap().to_string_lossy());
+        if KNOWN_FAILURES.contains(&rel.as_str()) {
+            continue;
+        }
+
+        let src = fs::read_to_string(&path).expect("failed to read test case");
+        let result = parse(&src);
+
+        if result.is_ok() != expect_success {
+            failures.push(rel);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} unexpected parser result(s) in `{}`: {:?}",
+        failures.len(),
+        dir,
+        failures
+    );
+}
+
+#[test]
+fn pass() {
+    run_directory("pass", true);
+}
+
+#[test]
+fn pass_explicit() {
+    run_directory("pass-explicit", true);
+}
+
+#[test]
+fn fail() {
+    run_directory("fail", false);
+}
+
+/// `early/` files are syntactically valid per the grammar and only fail under the early-error
+/// (static semantics) pass -- e.g. duplicate `let` bindings, invalid assignment targets -- which
+/// is a separate analysis this crate's parser does not perform. Asserting `ParseError` here would
+/// just be wrong for a parser-only harness, so this directory isn't run as a parse/no-parse check
+/// until that pass exists.
+#[test]
+#[ignore = "early-error static semantics not implemented by this parser yet"]
+fn early() {
+    run_directory("early", false);
+}